@@ -1,12 +1,15 @@
+use super::date::parse_relative_date;
 use super::status::Status;
+use chrono::NaiveDate;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Card {
     column: String,
     status: Status,
     title: String,
-    date: Option<String>,
+    date: Option<NaiveDate>,
     time: Option<String>,
+    id: Option<String>,
 }
 
 impl Card {
@@ -23,14 +26,26 @@ impl Card {
         &self.title
     }
 
-    pub fn date(&self) -> Option<String> {
-        self.date.clone()
+    pub fn date(&self) -> Option<NaiveDate> {
+        self.date
+    }
+
+    /// Whether this card's due date has passed and it is not yet done.
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        self.status != Status::Done && self.date.is_some_and(|date| date < today)
     }
 
     pub fn time(&self) -> Option<String> {
         self.time.clone()
     }
 
+    /// The card's stable identifier, assigned by `Kanban` when the card is
+    /// added to a board unless one was set through [`CardBuilder::id`].
+    /// `None` for a card built without an id that has not yet been added.
+    pub fn id(&self) -> Option<String> {
+        self.id.clone()
+    }
+
     pub fn mut_rename(mut self, new_name: &str) -> Self {
         self.title = new_name.to_string();
         self
@@ -42,6 +57,23 @@ impl Card {
             ..self.clone()
         }
     }
+
+    /// Returns a copy of this card with its `id` set.
+    /// Used by `Kanban` to assign stable ids on insertion.
+    pub(crate) fn with_id(&self, id: String) -> Self {
+        Card {
+            id: Some(id),
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy of this card with its due date set, or cleared if `None`.
+    pub(crate) fn with_date(&self, date: Option<NaiveDate>) -> Self {
+        Card {
+            date,
+            ..self.clone()
+        }
+    }
 }
 
 impl std::fmt::Display for Card {
@@ -50,16 +82,20 @@ impl std::fmt::Display for Card {
         let title = &self.title;
         let date = self
             .date
-            .as_ref()
-            .map(|d| format!(" @{{{}}}", d))
+            .map(|d| format!(" @{{{}}}", d.format("%Y-%m-%d")))
             .unwrap_or_default();
         let time = self
             .time
             .as_ref()
             .map(|t| format!(" @@{{{}}}", t))
             .unwrap_or_default();
+        let id = self
+            .id
+            .as_ref()
+            .map(|i| format!(" ^{{{}}}", i))
+            .unwrap_or_default();
 
-        write!(f, "- [{status}] {title}{date}{time}")
+        write!(f, "- [{status}] {title}{date}{time}{id}")
     }
 }
 
@@ -70,6 +106,7 @@ pub struct CardBuilder {
     title: Option<String>,
     date: Option<String>,
     time: Option<String>,
+    id: Option<String>,
 }
 
 impl CardBuilder {
@@ -92,6 +129,9 @@ impl CardBuilder {
         self
     }
 
+    /// Sets the card's due date. Accepts an absolute `YYYY-MM-DD` date or a
+    /// relative/natural form (`today`, `tomorrow`, `in 3 days`, `monday`,
+    /// ...), resolved against the current date at [`CardBuilder::build`].
     pub fn date(mut self, date: &str) -> Self {
         self.date = Some(date.to_string());
         self
@@ -102,16 +142,26 @@ impl CardBuilder {
         self
     }
 
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+
     pub fn build(self) -> Result<Card, String> {
         let column = self.column.ok_or("Column is required")?;
         let status = self.status.unwrap_or_default();
         let title = self.title.ok_or("Title is required")?;
+        let date = self
+            .date
+            .map(|d| parse_relative_date(&d, chrono::Local::now().date_naive()))
+            .transpose()?;
         Ok(Card {
             column,
             status,
             title,
-            date: self.date,
+            date,
             time: self.time,
+            id: self.id,
         })
     }
 }
@@ -180,4 +230,46 @@ mod tests {
         let renamed = card.mut_rename("New Title");
         assert_eq!(renamed.title(), "New Title");
     }
+
+    #[test]
+    fn test_card_id() {
+        let card = CardBuilder::new()
+            .column("Column")
+            .status(Status::Done)
+            .title("Title")
+            .id("1")
+            .build()
+            .unwrap();
+        assert_eq!(card.id(), Some("1".to_string()));
+        assert_eq!(card.to_string(), "- [x] Title ^{1}");
+    }
+
+    #[test]
+    fn test_card_with_id() {
+        let card = CardBuilder::new()
+            .column("Column")
+            .title("Title")
+            .build()
+            .unwrap();
+        assert_eq!(card.id(), None);
+        let card = card.with_id("42".to_string());
+        assert_eq!(card.id(), Some("42".to_string()));
+    }
+
+    #[test]
+    fn test_card_with_date() {
+        let card = CardBuilder::new()
+            .column("Column")
+            .title("Title")
+            .build()
+            .unwrap();
+        assert_eq!(card.date(), None);
+
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let card = card.with_date(Some(date));
+        assert_eq!(card.date(), Some(date));
+
+        let card = card.with_date(None);
+        assert_eq!(card.date(), None);
+    }
 }
@@ -0,0 +1,103 @@
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+/// Parses a due-date token relative to `today`.
+///
+/// Accepts an absolute `YYYY-MM-DD` date, the keywords `today`, `tomorrow`
+/// and `yesterday`, relative offsets like `in 3 days` / `in 2 weeks`, and
+/// bare weekday names (`monday`..`sunday`), which resolve to the next
+/// occurrence of that weekday strictly after `today`.
+pub fn parse_relative_date(token: &str, today: NaiveDate) -> Result<NaiveDate, String> {
+    let token = token.trim().to_lowercase();
+
+    match token.as_str() {
+        "today" => return Ok(today),
+        "tomorrow" => return Ok(today + Duration::days(1)),
+        "yesterday" => return Ok(today - Duration::days(1)),
+        _ => {}
+    }
+
+    if let Some(rest) = token.strip_prefix("in ") {
+        let mut parts = rest.split_whitespace();
+        let n: i64 = parts
+            .next()
+            .ok_or_else(|| format!("Expected a number after 'in': {token}"))?
+            .parse()
+            .map_err(|_| format!("Invalid number in relative date: {token}"))?;
+        let unit = parts
+            .next()
+            .ok_or_else(|| format!("Expected a unit after the number: {token}"))?;
+        let days = match unit {
+            "day" | "days" => n,
+            "week" | "weeks" => n * 7,
+            _ => return Err(format!("Unknown date unit: {unit}")),
+        };
+        return Ok(today + Duration::days(days));
+    }
+
+    if let Some(weekday) = parse_weekday(&token) {
+        let today_weekday = today.weekday().num_days_from_monday() as i64;
+        let target_weekday = weekday.num_days_from_monday() as i64;
+        let days_ahead = (target_weekday - today_weekday + 7 - 1) % 7 + 1;
+        return Ok(today + Duration::days(days_ahead));
+    }
+
+    NaiveDate::parse_from_str(&token, "%Y-%m-%d").map_err(|e| e.to_string())
+}
+
+fn parse_weekday(token: &str) -> Option<Weekday> {
+    match token {
+        "monday" => Some(Weekday::Mon),
+        "tuesday" => Some(Weekday::Tue),
+        "wednesday" => Some(Weekday::Wed),
+        "thursday" => Some(Weekday::Thu),
+        "friday" => Some(Weekday::Fri),
+        "saturday" => Some(Weekday::Sat),
+        "sunday" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_parse_absolute_date() {
+        let today = date(2024, 1, 15);
+        assert_eq!(parse_relative_date("2024-01-20", today), Ok(date(2024, 1, 20)));
+    }
+
+    #[test]
+    fn test_parse_keywords() {
+        let today = date(2024, 1, 15);
+        assert_eq!(parse_relative_date("today", today), Ok(today));
+        assert_eq!(parse_relative_date("Tomorrow", today), Ok(date(2024, 1, 16)));
+        assert_eq!(parse_relative_date("yesterday", today), Ok(date(2024, 1, 14)));
+    }
+
+    #[test]
+    fn test_parse_in_n_units() {
+        let today = date(2024, 1, 15);
+        assert_eq!(parse_relative_date("in 3 days", today), Ok(date(2024, 1, 18)));
+        assert_eq!(parse_relative_date("in 2 weeks", today), Ok(date(2024, 1, 29)));
+    }
+
+    #[test]
+    fn test_parse_weekday_is_strictly_after_today() {
+        // 2024-01-15 is a Monday.
+        let today = date(2024, 1, 15);
+        assert_eq!(parse_relative_date("monday", today), Ok(date(2024, 1, 22)));
+        assert_eq!(parse_relative_date("wednesday", today), Ok(date(2024, 1, 17)));
+        assert_eq!(parse_relative_date("sunday", today), Ok(date(2024, 1, 21)));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let today = date(2024, 1, 15);
+        assert!(parse_relative_date("whenever", today).is_err());
+    }
+}
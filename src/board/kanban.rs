@@ -1,4 +1,5 @@
 use super::card::{Card, CardBuilder};
+use super::parse_error::{Diagnostic, ParseError};
 use super::status::Status;
 use pest::Parser;
 use pest_derive::Parser;
@@ -9,10 +10,24 @@ use pest_derive::Parser;
 #[grammar = "kanban.pest"]
 pub struct KanbanParser;
 
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Kanban {
     columns: Vec<String>,
+    /// WIP limit for each column, aligned by index with `columns`.
+    column_limits: Vec<Option<usize>>,
     cards: Vec<Card>,
+    next_id: usize,
+}
+
+impl Default for Kanban {
+    fn default() -> Self {
+        Kanban {
+            columns: Vec::new(),
+            column_limits: Vec::new(),
+            cards: Vec::new(),
+            next_id: 1,
+        }
+    }
 }
 
 impl Kanban {
@@ -25,10 +40,12 @@ impl Kanban {
     /// let board = Kanban::new(&["To Do", "In Progress", "Done"]);
     /// ```
     pub fn new(columns: &[&str]) -> Self {
-        let columns = columns.iter().map(|c| c.to_string()).collect();
+        let columns: Vec<String> = columns.iter().map(|c| c.to_string()).collect();
+        let column_limits = vec![None; columns.len()];
         Kanban {
             columns,
-            cards: Vec::new(),
+            column_limits,
+            ..Kanban::default()
         }
     }
 
@@ -42,14 +59,87 @@ impl Kanban {
     /// board.add_column("To Do".to_string()).unwrap();
     /// ```
     pub fn add_column(&mut self, name: String) -> Result<(), String> {
+        self.add_column_with_limit(name, None)
+    }
+
+    /// Adds a new column with an optional WIP limit: once the column holds
+    /// `limit` cards, further [`Kanban::add_card`]/[`Kanban::move_card`]
+    /// calls targeting it are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kantui::Kanban;
+    /// let mut board = Kanban::default();
+    /// board.add_column_with_limit("In Progress".to_string(), Some(3)).unwrap();
+    /// ```
+    pub fn add_column_with_limit(
+        &mut self,
+        name: String,
+        limit: Option<usize>,
+    ) -> Result<(), String> {
         self.columns.push(name);
+        self.column_limits.push(limit);
 
         Ok(())
     }
 
+    /// Sets (or clears) the WIP limit for an existing column.
+    /// Returns an error if the column does not exist.
+    pub fn set_limit(&mut self, column: &str, limit: Option<usize>) -> Result<(), String> {
+        let pos = self.column_pos(column)?;
+        self.column_limits[pos] = limit;
+        Ok(())
+    }
+
+    /// The configured WIP limit for `column`, if any.
+    pub fn limit_for(&self, column: &str) -> Option<usize> {
+        self.column_pos(column).ok().and_then(|i| self.column_limits[i])
+    }
+
+    /// The number of cards currently in `column`.
+    pub fn count_in_column(&self, column: &str) -> usize {
+        self.cards.iter().filter(|c| c.column() == column).count()
+    }
+
+    /// Applies WIP limits from a loaded [`crate::Config`] to matching columns,
+    /// leaving columns the config doesn't mention unchanged.
+    pub fn apply_config(&mut self, config: &crate::config::Config) {
+        for i in 0..self.columns.len() {
+            if let Some(limit) = config.limit_for(&self.columns[i]) {
+                self.column_limits[i] = Some(limit);
+            }
+        }
+    }
+
+    /// Returns an error if adding or moving a card into `column` would
+    /// exceed its configured WIP limit.
+    fn check_limit(&self, column: &str) -> Result<(), String> {
+        if let Some(limit) = self.limit_for(column) {
+            let count = self.count_in_column(column);
+            if count >= limit {
+                return Err(format!(
+                    "WIP limit reached for column '{column}': {count}/{limit}"
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Internal helper: the index of `column` in `self.columns`.
+    fn column_pos(&self, column: &str) -> Result<usize, String> {
+        self.columns
+            .iter()
+            .position(|c| c == column)
+            .ok_or_else(|| "Column does not exist".to_string())
+    }
+
     /// Adds a card to the Kanban board in the specified column.
     /// Returns an error if the column does not exist.
     ///
+    /// A stable `id` is assigned automatically if `card` does not already
+    /// carry one (e.g. one freshly built with `CardBuilder`).
+    ///
     /// # Examples
     ///
     /// ```
@@ -67,7 +157,23 @@ impl Kanban {
     /// ```
     pub fn add_card(&mut self, card: &Card) -> Result<(), String> {
         self.has_column(card.column())?;
-        self.cards.push(card.clone());
+        self.check_limit(card.column())?;
+
+        let card = match card.id() {
+            Some(id) => {
+                if self.cards.iter().any(|c| c.id().as_deref() == Some(id.as_str())) {
+                    return Err(format!("Card id '{id}' is already in use"));
+                }
+                self.bump_next_id(&id);
+                card.clone()
+            }
+            None => {
+                let id = self.next_id.to_string();
+                self.next_id += 1;
+                card.with_id(id)
+            }
+        };
+        self.cards.push(card);
         Ok(())
     }
 
@@ -79,8 +185,25 @@ impl Kanban {
         }
     }
 
+    /// Ensures `next_id` stays ahead of any numeric id already in use, so
+    /// ids assigned to cards parsed from a file never collide with ids
+    /// assigned to cards added afterwards.
+    fn bump_next_id(&mut self, id: &str) {
+        if let Ok(n) = id.parse::<usize>() {
+            self.next_id = self.next_id.max(n + 1);
+        }
+    }
+
+    /// Finds a card by its id.
+    fn find_card_mut(&mut self, id: &str) -> Result<&mut Card, String> {
+        self.cards
+            .iter_mut()
+            .find(|c| c.id().as_deref() == Some(id))
+            .ok_or_else(|| "Card does not exist".to_string())
+    }
+
     /// Moves a card to a different column.
-    /// Returns an error if the target column does not exist.
+    /// Returns an error if the card or the target column does not exist.
     ///
     /// # Examples
     ///
@@ -97,25 +220,147 @@ impl Kanban {
     ///     .unwrap();
     ///
     /// board.add_card(&card).unwrap();
-    /// board.move_card(&"Done".to_string(), card).unwrap();
+    /// let id = board.cards()[0].id().unwrap();
+    /// board.move_card("Done", &id).unwrap();
     /// ```
-    pub fn move_card(&mut self, to: &String, card: Card) -> Result<(), String> {
-        self.has_column(to)?;
+    pub fn move_card(&mut self, to: &str, id: &str) -> Result<(), String> {
+        self.has_column(&to.to_string())?;
+        let current_column = self
+            .cards
+            .iter()
+            .find(|c| c.id().as_deref() == Some(id))
+            .map(|c| c.column().clone())
+            .ok_or_else(|| "Card does not exist".to_string())?;
+        if current_column != to {
+            self.check_limit(to)?;
+        }
+        self.find_card_mut(id)?.move_to(to);
+        Ok(())
+    }
 
-        self.cards = self
+    /// Removes a card from the board by id.
+    /// Returns an error if no card has that id.
+    ///
+    /// Like [`Kanban::rename_card`] and [`Kanban::move_card`], only the
+    /// first card with a matching id is affected.
+    pub fn remove_card(&mut self, id: &str) -> Result<(), String> {
+        let pos = self
             .cards
-            .clone()
-            .into_iter()
-            .map(|mut c| {
-                if c == card {
-                    c.move_to(to);
-                }
-                c
-            })
-            .collect();
+            .iter()
+            .position(|c| c.id().as_deref() == Some(id))
+            .ok_or_else(|| "Card does not exist".to_string())?;
+        self.cards.remove(pos);
+        Ok(())
+    }
+
+    /// Renames a card by id.
+    /// Returns an error if no card has that id.
+    pub fn rename_card(&mut self, id: &str, title: &str) -> Result<(), String> {
+        let card = self.find_card_mut(id)?;
+        *card = card.rename(title);
+        Ok(())
+    }
+
+    /// Sets or clears a card's due date by id.
+    ///
+    /// `date` accepts the same absolute (`YYYY-MM-DD`) or relative/natural
+    /// forms (`today`, `in 3 days`, `monday`, ...) as [`CardBuilder::date`];
+    /// `None` clears the due date.
+    /// Returns an error if no card has that id, or if `date` doesn't parse.
+    pub fn set_card_date(&mut self, id: &str, date: Option<&str>) -> Result<(), String> {
+        let date = date
+            .map(|d| super::date::parse_relative_date(d, chrono::Local::now().date_naive()))
+            .transpose()?;
+        let card = self.find_card_mut(id)?;
+        *card = card.with_date(date);
+        Ok(())
+    }
+
+    /// Removes a column and any cards it contains.
+    /// Returns an error if the column does not exist.
+    pub fn remove_column(&mut self, name: &str) -> Result<(), String> {
+        let pos = self.column_pos(name)?;
+        self.columns.remove(pos);
+        self.column_limits.remove(pos);
+        self.cards.retain(|c| c.column() != name);
         Ok(())
     }
 
+    /// Renames a column, moving every card it contains along with it.
+    /// Returns an error if the column does not exist.
+    pub fn rename_column(&mut self, name: &str, new_name: &str) -> Result<(), String> {
+        let column = self
+            .columns
+            .iter_mut()
+            .find(|c| c.as_str() == name)
+            .ok_or_else(|| "Column does not exist".to_string())?;
+        *column = new_name.to_string();
+
+        for card in self.cards.iter_mut().filter(|c| c.column() == name) {
+            card.move_to(new_name);
+        }
+        Ok(())
+    }
+
+    /// The board's columns, in order.
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// The board's cards, in insertion order.
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Cards with a due date strictly before `date`, in board order.
+    pub fn cards_due_before(&self, date: chrono::NaiveDate) -> Vec<&Card> {
+        self.cards
+            .iter()
+            .filter(|c| c.date().is_some_and(|d| d < date))
+            .collect()
+    }
+
+    /// Renders the whole board as a side-by-side table, one terminal
+    /// column per Kanban column, word-wrapped to fit within `width`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kantui::Kanban;
+    /// let board = Kanban::new(&["To Do", "Done"]);
+    /// println!("{}", board.render_table(80));
+    /// ```
+    pub fn render_table(&self, width: usize) -> String {
+        super::table::render(
+            &self.columns,
+            &self.column_limits,
+            &self.cards,
+            width,
+            chrono::Local::now().date_naive(),
+        )
+    }
+
+    /// Renders a single column as a table, for the CLI's per-column view.
+    /// Returns an error if the column does not exist.
+    pub fn render_column_table(&self, column: &str, width: usize) -> Result<String, String> {
+        let pos = self.column_pos(column)?;
+        let columns = vec![column.to_string()];
+        let limits = vec![self.column_limits[pos]];
+        let cards: Vec<Card> = self
+            .cards
+            .iter()
+            .filter(|c| c.column() == column)
+            .cloned()
+            .collect();
+        Ok(super::table::render(
+            &columns,
+            &limits,
+            &cards,
+            width,
+            chrono::Local::now().date_naive(),
+        ))
+    }
+
     /// Parses a Kanban board from a string in the markdown-like format.
     /// Returns an error if the input is invalid.
     ///
@@ -130,11 +375,12 @@ impl Kanban {
     ///
     /// let board = Kanban::parse(input).unwrap();
     /// ```
-    pub fn parse(input: &str) -> Result<Self, String> {
-        let pairs = KanbanParser::parse(Rule::kanban, input).map_err(|e| e.to_string())?;
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let pairs = KanbanParser::parse(Rule::kanban, input)?;
 
         let mut kanban = Kanban::default();
         let mut current_column = String::new();
+        let mut diagnostics = Vec::new();
 
         for pair in pairs.into_iter().next().unwrap().into_inner() {
             match pair.as_rule() {
@@ -142,14 +388,18 @@ impl Kanban {
                     for inner in pair.into_inner() {
                         if inner.as_rule() == Rule::text {
                             current_column = inner.as_str().to_string();
-                            kanban.add_column(current_column.clone())?;
+                            // `add_column` only ever pushes; it cannot fail.
+                            let _ = kanban.add_column(current_column.clone());
                         }
                     }
                 }
                 Rule::card => {
+                    let card_span = pair.as_span();
                     let mut card_text = String::new();
                     let mut status = Status::Incomplete;
                     let mut date: Option<String> = None;
+                    let mut date_span = card_span;
+                    let mut id: Option<String> = None;
 
                     for part in pair.into_inner() {
                         match part.as_rule() {
@@ -158,15 +408,24 @@ impl Kanban {
                                 status = match status_inner.as_rule() {
                                     Rule::complete => Status::Done,
                                     Rule::incomplete => Status::Incomplete,
-                                    _ => return Err("Invalid status".to_string()),
+                                    _ => {
+                                        diagnostics.push(Diagnostic::from_span(
+                                            status_inner.as_span(),
+                                            "Invalid status".to_string(),
+                                        ));
+                                        Status::Incomplete
+                                    }
                                 };
                             }
                             Rule::text => {
                                 card_text = part.as_str().to_string();
                             }
                             Rule::date => {
-                                let date_str = part.as_str();
-                                date = Some(date_str.to_string());
+                                date_span = part.as_span();
+                                date = Some(part.as_str().to_string());
+                            }
+                            Rule::id => {
+                                id = Some(part.as_str().to_string());
                             }
                             _ => {}
                         }
@@ -176,24 +435,125 @@ impl Kanban {
                         .column(&current_column)
                         .title(&card_text)
                         .status(status);
-                    if let Some(date) = date {
-                        card = card.date(&date);
+                    if let Some(date) = &date {
+                        card = card.date(date);
+                    }
+                    if let Some(id) = &id {
+                        card = card.id(id);
                     }
-                    let card = card.build()?;
 
-                    kanban.add_card(&card)?;
+                    match card.build() {
+                        Ok(card) => {
+                            if let Err(e) = kanban.add_card(&card) {
+                                diagnostics.push(Diagnostic::from_span(card_span, e));
+                            }
+                        }
+                        Err(e) => diagnostics.push(Diagnostic::from_span(date_span, e)),
+                    }
                 }
                 _ => {}
             }
         }
 
-        Ok(kanban)
+        if diagnostics.is_empty() {
+            Ok(kanban)
+        } else {
+            Err(ParseError { diagnostics })
+        }
+    }
+
+    /// Writes the board back to `path` in the same markdown-like format
+    /// accepted by [`Kanban::parse`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use kantui::Kanban;
+    ///
+    /// let board = Kanban::new(&["To Do"]);
+    /// board.save_to_file("board.md").unwrap();
+    /// ```
+    pub fn save_to_file(&self, path: impl AsRef<std::path::Path>) -> Result<(), std::io::Error> {
+        std::fs::write(path, self.to_string())
+    }
+
+    /// Reads a file and parses it into a [`Kanban`] board.
+    /// Returns an error if the file cannot be read or the contents are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use kantui::Kanban;
+    ///
+    /// let board = Kanban::load_from_file("board.md").unwrap();
+    /// ```
+    pub fn load_from_file(path: impl AsRef<std::path::Path>) -> Result<Self, String> {
+        let input = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        Self::parse(&input).map_err(|e| e.to_string())
+    }
+
+    /// Like [`Kanban::load_from_file`], but consults `con` first and only
+    /// re-parses when the file's contents have changed since the last call.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use kantui::{Cached, Kanban};
+    /// use rusqlite::Connection;
+    ///
+    /// let con = Connection::open("cache.sqlite").unwrap();
+    /// kantui::BoardCache::init(&con).unwrap();
+    /// let board = Kanban::load_from_file_cached("board.md", &con).unwrap();
+    /// ```
+    pub fn load_from_file_cached(
+        path: impl AsRef<std::path::Path>,
+        con: &rusqlite::Connection,
+    ) -> Result<Self, crate::cache::CachedError<String>> {
+        let bytes =
+            std::fs::read(path).map_err(|e| crate::cache::CachedError::Gen(e.to_string()))?;
+        let key = crate::cache::hash_bytes(&bytes);
+
+        crate::cache::get_or_insert::<crate::cache::BoardCache, _>(con, &key, || {
+            String::from_utf8(bytes)
+                .map_err(|e| e.to_string())
+                .and_then(|s| Kanban::parse(&s).map_err(|e| e.to_string()))
+        })
+    }
+}
+
+impl std::str::FromStr for Kanban {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Kanban::parse(s).map_err(|e| e.to_string())
+    }
+}
+
+impl std::fmt::Display for Kanban {
+    /// Emits each column as a `## Heading` followed by its cards in
+    /// insertion order, using [`Card`]'s own `Display` format, so that
+    /// `Kanban::parse(board.to_string())` yields the same columns and cards.
+    /// Column WIP limits are not written, so they are `None` on the
+    /// re-parsed board.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for (i, column) in self.columns.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "## {column}")?;
+            writeln!(f)?;
+            for card in self.cards.iter().filter(|c| c.column() == column) {
+                writeln!(f, "{card}")?;
+            }
+        }
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::cache::Cached;
     const TEST_INPUT: &str = r#"## In Progress
 
 - [ ] I'm doing it!!"#;
@@ -248,7 +608,24 @@ mod test {
         assert_eq!(kanban.columns, vec!["To Do".to_string()]);
         assert_eq!(kanban.cards.len(), 2);
         assert_eq!(kanban.cards[0].title().trim(), "Task with date");
-        assert_eq!(kanban.cards[0].date(), Some("2024-01-15".to_string()));
+        assert_eq!(
+            kanban.cards[0].date(),
+            Some(chrono::NaiveDate::from_ymd_opt(2024, 1, 15).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_cards_due_before() {
+        let input = r#"## To Do
+
+- [ ] Overdue task @{2024-01-01}
+- [ ] Future task @{2024-02-01}
+- [ ] No date task
+"#;
+        let kanban = Kanban::parse(input).unwrap();
+        let due = kanban.cards_due_before(chrono::NaiveDate::from_ymd_opt(2024, 1, 15).unwrap());
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].title().trim(), "Overdue task");
     }
 
     #[test]
@@ -267,4 +644,248 @@ mod test {
             .unwrap();
         assert_eq!(parser.into_inner().len(), 3);
     }
+
+    #[test]
+    fn test_round_trip() {
+        let input = "## In Progress\n\n- [ ] I'm doing it!!\n";
+        let kanban = Kanban::parse(input).unwrap();
+        assert_eq!(Kanban::parse(&kanban.to_string()).unwrap(), kanban);
+    }
+
+    #[test]
+    fn test_round_trip_with_id() {
+        // `add_card` assigns every card an id, so every card `Display`s with
+        // a trailing ` ^{id}` marker. The rendered output must parse back
+        // into an identical board.
+        let input = "## To Do\n\n- [ ] Task with id\n";
+        let kanban = Kanban::parse(input).unwrap();
+        let rendered = kanban.to_string();
+        assert!(rendered.contains("^{1}"));
+        assert_eq!(Kanban::parse(&rendered).unwrap(), kanban);
+    }
+
+    #[test]
+    fn test_title_with_bare_at_and_caret_parses() {
+        // Only ` @{`/` @@{`/` ^{` are markers; a bare `@`/`^` in a title
+        // (an email address, an exponent) must not terminate it.
+        let input = "## To Do\n\n- [ ] Email user@host.com\n- [ ] Fix x^2 formula\n";
+        let kanban = Kanban::parse(input).unwrap();
+        assert_eq!(kanban.cards[0].title(), "Email user@host.com");
+        assert_eq!(kanban.cards[1].title(), "Fix x^2 formula");
+    }
+
+    #[test]
+    fn test_add_card_rejects_duplicate_explicit_id() {
+        let input = "## To Do\n\n- [ ] One ^{5}\n- [ ] Two ^{5}\n";
+        let err = Kanban::parse(input).unwrap_err();
+        assert_eq!(err.diagnostics().len(), 1);
+    }
+
+    #[test]
+    fn test_remove_card_by_explicit_id() {
+        let mut kanban = Kanban::default();
+        kanban.add_column("Test Column".to_string()).unwrap();
+        let card = CardBuilder::new()
+            .column("Test Column")
+            .title("One")
+            .id("5")
+            .build()
+            .unwrap();
+        kanban.add_card(&card).unwrap();
+
+        kanban.remove_card("5").unwrap();
+        assert!(kanban.cards.is_empty());
+    }
+
+    #[test]
+    fn test_add_card_assigns_id() {
+        let mut kanban = Kanban::default();
+        kanban.add_column("Test Column".to_string()).unwrap();
+
+        let card = CardBuilder::new()
+            .column("Test Column")
+            .title("First")
+            .build()
+            .unwrap();
+        kanban.add_card(&card).unwrap();
+
+        let card = CardBuilder::new()
+            .column("Test Column")
+            .title("Second")
+            .build()
+            .unwrap();
+        kanban.add_card(&card).unwrap();
+
+        assert_eq!(kanban.cards[0].id(), Some("1".to_string()));
+        assert_eq!(kanban.cards[1].id(), Some("2".to_string()));
+    }
+
+    #[test]
+    fn test_remove_rename_move_card_by_id() {
+        let mut kanban = Kanban::default();
+        kanban.add_column("To Do".to_string()).unwrap();
+        kanban.add_column("Done".to_string()).unwrap();
+
+        let card = CardBuilder::new()
+            .column("To Do")
+            .title("Task")
+            .build()
+            .unwrap();
+        kanban.add_card(&card).unwrap();
+        let id = kanban.cards[0].id().unwrap();
+
+        kanban.rename_card(&id, "Renamed Task").unwrap();
+        assert_eq!(kanban.cards[0].title(), "Renamed Task");
+
+        kanban.move_card("Done", &id).unwrap();
+        assert_eq!(kanban.cards[0].column(), "Done");
+
+        kanban.remove_card(&id).unwrap();
+        assert!(kanban.cards.is_empty());
+        assert!(kanban.remove_card(&id).is_err());
+    }
+
+    #[test]
+    fn test_set_card_date() {
+        let mut kanban = Kanban::default();
+        kanban.add_column("To Do".to_string()).unwrap();
+
+        let card = CardBuilder::new()
+            .column("To Do")
+            .title("Task")
+            .build()
+            .unwrap();
+        kanban.add_card(&card).unwrap();
+        let id = kanban.cards[0].id().unwrap();
+
+        kanban.set_card_date(&id, Some("2024-01-15")).unwrap();
+        assert_eq!(
+            kanban.cards[0].date(),
+            Some(chrono::NaiveDate::from_ymd_opt(2024, 1, 15).unwrap())
+        );
+
+        kanban.set_card_date(&id, None).unwrap();
+        assert_eq!(kanban.cards[0].date(), None);
+    }
+
+    #[test]
+    fn test_remove_and_rename_column() {
+        let mut kanban = Kanban::default();
+        kanban.add_column("To Do".to_string()).unwrap();
+        let card = CardBuilder::new()
+            .column("To Do")
+            .title("Task")
+            .build()
+            .unwrap();
+        kanban.add_card(&card).unwrap();
+
+        kanban.rename_column("To Do", "Backlog").unwrap();
+        assert_eq!(kanban.columns, vec!["Backlog".to_string()]);
+        assert_eq!(kanban.cards[0].column(), "Backlog");
+
+        kanban.remove_column("Backlog").unwrap();
+        assert!(kanban.columns.is_empty());
+        assert!(kanban.cards.is_empty());
+    }
+
+    #[test]
+    fn test_load_from_file_cached() {
+        let path = std::env::temp_dir().join("kantui_test_load_from_file_cached.md");
+        std::fs::write(&path, "## To Do\n\n- [ ] Task\n").unwrap();
+
+        let con = rusqlite::Connection::open_in_memory().unwrap();
+        crate::BoardCache::init(&con).unwrap();
+
+        let first = Kanban::load_from_file_cached(&path, &con).unwrap();
+        let second = Kanban::load_from_file_cached(&path, &con).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(first.columns, vec!["To Do".to_string()]);
+    }
+
+    #[test]
+    fn test_save_and_load_file() {
+        let path = std::env::temp_dir().join("kantui_test_save_and_load_file.md");
+
+        let mut kanban = Kanban::default();
+        kanban.add_column("To Do".to_string()).unwrap();
+        let card = CardBuilder::new()
+            .column("To Do")
+            .title("Write tests")
+            .build()
+            .unwrap();
+        kanban.add_card(&card).unwrap();
+
+        kanban.save_to_file(&path).unwrap();
+        let loaded = Kanban::load_from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, kanban);
+    }
+
+    #[test]
+    fn test_wip_limit_rejects_add_and_move_over_limit() {
+        let mut kanban = Kanban::default();
+        kanban
+            .add_column_with_limit("In Progress".to_string(), Some(1))
+            .unwrap();
+        kanban.add_column("To Do".to_string()).unwrap();
+
+        let first = CardBuilder::new()
+            .column("In Progress")
+            .title("First")
+            .build()
+            .unwrap();
+        kanban.add_card(&first).unwrap();
+
+        let second = CardBuilder::new()
+            .column("In Progress")
+            .title("Second")
+            .build()
+            .unwrap();
+        assert!(kanban.add_card(&second).is_err());
+
+        let third = CardBuilder::new()
+            .column("To Do")
+            .title("Third")
+            .build()
+            .unwrap();
+        kanban.add_card(&third).unwrap();
+        let third_id = kanban.cards[1].id().unwrap();
+        assert!(kanban.move_card("In Progress", &third_id).is_err());
+    }
+
+    #[test]
+    fn test_move_card_into_own_full_column_is_noop() {
+        let mut kanban = Kanban::default();
+        kanban
+            .add_column_with_limit("In Progress".to_string(), Some(1))
+            .unwrap();
+
+        let card = CardBuilder::new()
+            .column("In Progress")
+            .title("Task")
+            .build()
+            .unwrap();
+        kanban.add_card(&card).unwrap();
+        let id = kanban.cards[0].id().unwrap();
+
+        kanban.move_card("In Progress", &id).unwrap();
+    }
+
+    #[test]
+    fn test_apply_config_sets_limits() {
+        let mut kanban = Kanban::default();
+        kanban.add_column("In Progress".to_string()).unwrap();
+
+        let mut config = crate::config::Config::default();
+        config.columns.insert(
+            "In Progress".to_string(),
+            crate::config::ColumnConfig { limit: Some(2) },
+        );
+        kanban.apply_config(&config);
+
+        assert_eq!(kanban.limit_for("In Progress"), Some(2));
+    }
 }
@@ -0,0 +1,6 @@
+pub mod card;
+pub mod date;
+pub mod kanban;
+pub mod parse_error;
+pub mod status;
+mod table;
@@ -0,0 +1,168 @@
+use pest::error::{Error as PestError, ErrorVariant, LineColLocation};
+use pest::Span;
+use std::fmt;
+
+use super::kanban::Rule;
+
+/// A single problem found while parsing a Kanban board: where it is in the
+/// source, and what's wrong. Rendered with a line-number gutter, the
+/// offending source line, and a `^^^` marker under the relevant span.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    line: usize,
+    column: usize,
+    source_line: String,
+    span_len: usize,
+    message: String,
+    expected: Vec<String>,
+}
+
+impl Diagnostic {
+    /// Builds a diagnostic pointing at `span` with `message`. Used for
+    /// recoverable card/status problems found after the grammar itself
+    /// parsed successfully (e.g. a date that doesn't resolve to a real day).
+    pub(super) fn from_span(span: Span<'_>, message: String) -> Self {
+        let (line, column) = span.start_pos().line_col();
+        let source_line = span
+            .start_pos()
+            .line_of()
+            .trim_end_matches(['\r', '\n'])
+            .to_string();
+        let span_len = span.as_str().len().max(1);
+        Diagnostic {
+            line,
+            column,
+            source_line,
+            span_len,
+            message,
+            expected: Vec::new(),
+        }
+    }
+
+    /// The 1-indexed source line this diagnostic points at.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The 1-indexed column this diagnostic points at.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// The human-readable description of the problem.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl From<PestError<Rule>> for Diagnostic {
+    fn from(e: PestError<Rule>) -> Self {
+        let (line, column) = match &e.line_col {
+            LineColLocation::Pos((line, column)) => (*line, *column),
+            LineColLocation::Span((line, column), _) => (*line, *column),
+        };
+        let source_line = e.line().to_string();
+        let expected = match &e.variant {
+            ErrorVariant::ParsingError { positives, .. } => {
+                positives.iter().map(|rule| format!("{rule:?}")).collect()
+            }
+            ErrorVariant::CustomError { .. } => Vec::new(),
+        };
+        let message = e.variant.message().into_owned();
+
+        Diagnostic {
+            line,
+            column,
+            source_line,
+            span_len: 1,
+            message,
+            expected,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "error: {}", self.message)?;
+        let gutter = self.line.to_string();
+        let pad = " ".repeat(gutter.len());
+        writeln!(f, "{pad}--> line {}:{}", self.line, self.column)?;
+        writeln!(f, "{pad} |")?;
+        writeln!(f, "{gutter} | {}", self.source_line)?;
+        let indent = " ".repeat(self.column.saturating_sub(1));
+        let carets = "^".repeat(self.span_len);
+        if self.expected.is_empty() {
+            write!(f, "{pad} | {indent}{carets}")
+        } else {
+            write!(
+                f,
+                "{pad} | {indent}{carets} (expected one of: {})",
+                self.expected.join(", ")
+            )
+        }
+    }
+}
+
+/// All problems found while parsing a Kanban board. A malformed grammar
+/// construct (a stray character, an unterminated heading) yields a single
+/// diagnostic; card-level problems are collected across the whole file so a
+/// user editing a board sees every problem at once instead of fixing them
+/// one parse attempt at a time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub(super) diagnostics: Vec<Diagnostic>,
+}
+
+impl ParseError {
+    /// The individual problems found, in source order.
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+}
+
+impl From<PestError<Rule>> for ParseError {
+    fn from(e: PestError<Rule>) -> Self {
+        ParseError {
+            diagnostics: vec![Diagnostic::from(e)],
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, diagnostic) in self.diagnostics.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "{diagnostic}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::kanban::Kanban;
+
+    #[test]
+    fn test_grammar_error_renders_location() {
+        let err = Kanban::parse("not a valid board @@@").unwrap_err();
+        let diagnostics = err.diagnostics();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].line(), 1);
+        let rendered = err.to_string();
+        assert!(rendered.contains("--> line 1:"));
+        assert!(rendered.contains("not a valid board @@@"));
+    }
+
+    #[test]
+    fn test_collects_multiple_card_errors() {
+        // Both dates are syntactically valid (matched by the grammar's
+        // `date` rule) but don't resolve to real calendar days.
+        let input = "## To Do\n\n- [ ] First @{2024-13-40}\n- [ ] Second @{2024-02-30}\n";
+        let err = Kanban::parse(input).unwrap_err();
+        assert_eq!(err.diagnostics().len(), 2);
+    }
+}
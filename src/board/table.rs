@@ -0,0 +1,219 @@
+use super::card::Card;
+use super::status::Status;
+use chrono::NaiveDate;
+
+/// Box-drawing characters used to frame the table.
+const VERT: char = '│';
+
+/// Renders `cards` grouped by `columns` as a side-by-side grid, one
+/// terminal column per Kanban column, wrapped to fit within `width`
+/// columns total (including borders). Each column is at least 8 characters
+/// wide, so the table is wider than `width` when `width` is too small for that.
+///
+/// Each cell shows a card's status checkbox, title and due date, with an
+/// `(overdue)` marker for cards due before `today` that are not done. A
+/// column with a WIP limit set (see `limits`, aligned by index with
+/// `columns`) shows its current count against that limit in the header,
+/// e.g. `In Progress (2/3)`.
+pub(super) fn render(
+    columns: &[String],
+    limits: &[Option<usize>],
+    cards: &[Card],
+    width: usize,
+    today: NaiveDate,
+) -> String {
+    if columns.is_empty() {
+        return String::new();
+    }
+
+    let col_width = ((width.saturating_sub(columns.len() + 1)) / columns.len()).max(8);
+
+    let blocks: Vec<Vec<String>> = columns
+        .iter()
+        .map(|column| {
+            cards
+                .iter()
+                .filter(|c| c.column() == column)
+                .flat_map(|card| wrap(&card_line(card, today), col_width))
+                .collect()
+        })
+        .collect();
+    let rows = blocks.iter().map(Vec::len).max().unwrap_or(0);
+
+    let headers = columns.iter().zip(limits).map(|(column, limit)| {
+        let count = cards.iter().filter(|c| c.column() == column).count();
+        match limit {
+            Some(limit) => format!("{column} ({count}/{limit})"),
+            None => column.clone(),
+        }
+    });
+
+    let mut out = String::new();
+    out.push_str(&border(columns.len(), col_width, '┌', '┬', '┐'));
+    out.push_str(&row(headers.map(|h| pad(&h, col_width))));
+    out.push_str(&border(columns.len(), col_width, '├', '┼', '┤'));
+    for i in 0..rows {
+        let cells = blocks
+            .iter()
+            .map(|block| pad(block.get(i).map(String::as_str).unwrap_or(""), col_width));
+        out.push_str(&row(cells));
+    }
+    out.push_str(&border(columns.len(), col_width, '└', '┴', '┘'));
+    out
+}
+
+/// A single card's header line: checkbox, title, due date and overdue marker.
+fn card_line(card: &Card, today: NaiveDate) -> String {
+    let checkbox = if *card.status() == Status::Done {
+        "[x]"
+    } else {
+        "[ ]"
+    };
+    let mut line = format!("{checkbox} {}", card.title());
+    if let Some(date) = card.date() {
+        line.push_str(&format!(" @{{{}}}", date.format("%Y-%m-%d")));
+    }
+    if card.is_overdue(today) {
+        line.push_str(" (overdue)");
+    }
+    line
+}
+
+/// Greedily word-wraps `text` into lines no wider than `width` bytes; a word
+/// longer than `width` is kept whole on its own line.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+
+    for word in text.split_whitespace() {
+        let candidate_len = if line.is_empty() {
+            word.len()
+        } else {
+            line.len() + 1 + word.len()
+        };
+        if candidate_len > width && !line.is_empty() {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+    lines
+}
+
+/// Left-aligns and space-pads `text` to `width` characters, truncating on a
+/// char boundary if it overflows.
+fn pad(text: &str, width: usize) -> String {
+    if text.chars().count() > width {
+        text.chars().take(width).collect()
+    } else {
+        format!("{text:<width$}")
+    }
+}
+
+/// Joins already-padded `cells` into a single bordered row.
+fn row(cells: impl Iterator<Item = String>) -> String {
+    let mut line = String::new();
+    line.push(VERT);
+    for cell in cells {
+        line.push_str(&cell);
+        line.push(VERT);
+    }
+    line.push('\n');
+    line
+}
+
+/// Builds a horizontal border line using `left`/`mid`/`right` corner characters.
+fn border(count: usize, col_width: usize, left: char, mid: char, right: char) -> String {
+    let mut line = String::new();
+    line.push(left);
+    for i in 0..count {
+        if i > 0 {
+            line.push(mid);
+        }
+        line.push_str(&"─".repeat(col_width));
+    }
+    line.push(right);
+    line.push('\n');
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::card::CardBuilder;
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 1, 15).unwrap()
+    }
+
+    #[test]
+    fn test_render_single_column() {
+        let columns = vec!["To Do".to_string()];
+        let card = CardBuilder::new()
+            .column("To Do")
+            .title("Task")
+            .build()
+            .unwrap();
+        let table = render(&columns, &[None], &[card], 40, today());
+        assert!(table.contains("To Do"));
+        assert!(table.contains("[ ] Task"));
+        assert!(table.starts_with('┌'));
+    }
+
+    #[test]
+    fn test_render_marks_overdue() {
+        let columns = vec!["To Do".to_string()];
+        let card = CardBuilder::new()
+            .column("To Do")
+            .title("Task")
+            .date("2024-01-01")
+            .build()
+            .unwrap();
+        let table = render(&columns, &[None], &[card], 60, today());
+        assert!(table.contains("(overdue)"));
+    }
+
+    #[test]
+    fn test_render_shows_limit_in_header() {
+        let columns = vec!["In Progress".to_string()];
+        let card = CardBuilder::new()
+            .column("In Progress")
+            .title("Task")
+            .build()
+            .unwrap();
+        let table = render(&columns, &[Some(3)], &[card], 60, today());
+        assert!(table.contains("In Progress (1/3)"));
+    }
+
+    #[test]
+    fn test_wrap_splits_long_text() {
+        let lines = wrap("one two three four", 8);
+        assert_eq!(lines, vec!["one two", "three", "four"]);
+    }
+
+    #[test]
+    fn test_pad_truncates_multibyte_text_on_char_boundary() {
+        let padded = pad("😀😀😀😀😀", 3);
+        assert_eq!(padded.chars().count(), 3);
+    }
+
+    #[test]
+    fn test_render_does_not_panic_on_multibyte_title() {
+        let columns = vec!["To Do".to_string()];
+        let card = CardBuilder::new()
+            .column("To Do")
+            .title("😀😀😀😀😀😀😀😀😀😀")
+            .build()
+            .unwrap();
+        let table = render(&columns, &[None], &[card], 11, today());
+        assert!(!table.is_empty());
+    }
+}
@@ -0,0 +1,162 @@
+//! A small generic cache layer backed by SQLite.
+//!
+//! Boards are expensive to re-parse once they grow large, so callers that
+//! read the same file repeatedly can key a [`Cached`] implementation by a
+//! hash of the file's raw bytes: an unchanged file hits the cache, a
+//! changed file re-parses and refreshes it.
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+/// Error returned by [`get_or_insert`]: either the SQL layer failed, or the
+/// generator closure `f` failed while producing a fresh value.
+#[derive(Debug)]
+pub enum CachedError<E> {
+    Sql(rusqlite::Error),
+    Gen(E),
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for CachedError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CachedError::Sql(e) => write!(f, "cache error: {e}"),
+            CachedError::Gen(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl<E: std::fmt::Debug + std::fmt::Display> std::error::Error for CachedError<E> {}
+
+impl<E> From<rusqlite::Error> for CachedError<E> {
+    fn from(e: rusqlite::Error) -> Self {
+        CachedError::Sql(e)
+    }
+}
+
+/// A value keyed by `Key` and persisted as `Value` in a dedicated SQLite table.
+pub trait Cached: Sized {
+    type Key: ToString;
+    type Value: ToString + std::str::FromStr;
+
+    /// Name of the SQLite table backing this cache.
+    fn sql_table() -> &'static str;
+
+    /// Creates the backing table if it does not already exist.
+    fn init(con: &Connection) -> rusqlite::Result<()> {
+        con.execute(
+            &format!(
+                "CREATE TABLE IF NOT EXISTS {} (key TEXT PRIMARY KEY, value TEXT NOT NULL)",
+                Self::sql_table()
+            ),
+            [],
+        )?;
+        Ok(())
+    }
+
+    /// Looks up `key`, returning the deserialized value if present.
+    fn sql_get(con: &Connection, key: &Self::Key) -> rusqlite::Result<Option<Self::Value>> {
+        let raw: Option<String> = con
+            .query_row(
+                &format!("SELECT value FROM {} WHERE key = ?1", Self::sql_table()),
+                params![key.to_string()],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(raw.and_then(|v| v.parse().ok()))
+    }
+
+    /// Stores `value` under `key`, replacing any existing entry.
+    fn sql_set(con: &Connection, key: &Self::Key, value: &Self::Value) -> rusqlite::Result<()> {
+        con.execute(
+            &format!(
+                "INSERT OR REPLACE INTO {} (key, value) VALUES (?1, ?2)",
+                Self::sql_table()
+            ),
+            params![key.to_string(), value.to_string()],
+        )?;
+        Ok(())
+    }
+}
+
+/// Returns the cached value for `key`, computing it with `f` and storing it
+/// on a cache miss.
+pub fn get_or_insert<C, E>(
+    con: &Connection,
+    key: &C::Key,
+    f: impl FnOnce() -> Result<C::Value, E>,
+) -> Result<C::Value, CachedError<E>>
+where
+    C: Cached,
+{
+    if let Some(value) = C::sql_get(con, key)? {
+        return Ok(value);
+    }
+
+    let value = f().map_err(CachedError::Gen)?;
+    C::sql_set(con, key, &value)?;
+    Ok(value)
+}
+
+/// Cache of parsed [`crate::Kanban`] boards, keyed by a hash of the source
+/// file's raw bytes.
+pub struct BoardCache;
+
+impl Cached for BoardCache {
+    type Key = String;
+    type Value = crate::Kanban;
+
+    fn sql_table() -> &'static str {
+        "board_cache"
+    }
+}
+
+/// Hashes `bytes` into a hex-encoded cache key.
+pub fn hash_bytes(bytes: &[u8]) -> String {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    format!("{:x}", hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_connection() -> Connection {
+        let con = Connection::open_in_memory().unwrap();
+        BoardCache::init(&con).unwrap();
+        con
+    }
+
+    #[test]
+    fn test_get_or_insert_caches_on_miss() {
+        let con = test_connection();
+        let key = hash_bytes(b"## To Do\n\n- [ ] Task\n");
+        let mut calls = 0;
+
+        let board = get_or_insert::<BoardCache, String>(&con, &key, || {
+            calls += 1;
+            crate::Kanban::parse("## To Do\n\n- [ ] Task\n").map_err(|e| e.to_string())
+        })
+        .unwrap();
+        assert_eq!(board.columns(), &["To Do".to_string()]);
+        assert_eq!(calls, 1);
+
+        let cached = get_or_insert::<BoardCache, String>(&con, &key, || {
+            calls += 1;
+            crate::Kanban::parse("## To Do\n\n- [ ] Task\n").map_err(|e| e.to_string())
+        })
+        .unwrap();
+        assert_eq!(cached, board);
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_get_or_insert_propagates_generator_error() {
+        let con = test_connection();
+        let key = hash_bytes(b"not a board");
+
+        let result =
+            get_or_insert::<BoardCache, String>(&con, &key, || Err("bad input".to_string()));
+        assert!(matches!(result, Err(CachedError::Gen(_))));
+    }
+}
@@ -1,8 +1,18 @@
 use clap::{Parser, Subcommand};
+use kantui::{CardBuilder, Config, Kanban};
+use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None, after_help = "If no command is given, a TUI interface will be launched")]
 pub struct Cli {
+    /// Path to the Kanban board file
+    #[arg(short, long, global = true, default_value = "board.md")]
+    pub file: PathBuf,
+
+    /// Path to a TOML config file for per-column WIP limits
+    #[arg(short, long, global = true, default_value = "kantui.toml")]
+    pub config: PathBuf,
+
     #[command(subcommand)]
     pub command: Option<Command>,
 }
@@ -22,7 +32,12 @@ pub enum Command {
 #[derive(Subcommand, Clone, Debug)]
 pub enum ColumnCommands {
     #[command(about = "Add a new column")]
-    Add { title: String },
+    Add {
+        title: String,
+        /// WIP limit for the new column
+        #[arg(long)]
+        limit: Option<usize>,
+    },
     #[command(about = "List cards in a column")]
     List { id: String },
     #[command(about = "Remove a column")]
@@ -34,56 +49,101 @@ pub enum ColumnCommands {
 #[derive(Subcommand, Clone, Debug)]
 pub enum CardCommands {
     #[command(about = "Add a new card to a column")]
-    Add { title: String, column: String },
+    Add {
+        title: String,
+        column: String,
+        /// Due date: an absolute `YYYY-MM-DD` date or a relative/natural
+        /// form (`today`, `in 3 days`, `monday`, ...)
+        #[arg(long)]
+        date: Option<String>,
+    },
     #[command(about = "Remove a card")]
     Remove { id: String },
     #[command(about = "Rename a card")]
     Rename { id: String, title: String },
     #[command(about = "Move a card to a different column")]
     Move { id: String, column: String },
+    #[command(about = "Set or clear a card's due date")]
+    Date {
+        id: String,
+        /// Due date to set; omit to clear the card's due date
+        date: Option<String>,
+    },
+}
+
+/// The terminal width to render tables at, read from `$COLUMNS` with a
+/// sensible fallback when it isn't set (e.g. when output is piped).
+fn terminal_width() -> usize {
+    std::env::var("COLUMNS")
+        .ok()
+        .and_then(|c| c.parse().ok())
+        .unwrap_or(80)
+}
+
+/// Loads the board from `path`, or an empty board if the file does not exist yet.
+fn load_board(path: &PathBuf) -> Result<Kanban, Box<dyn std::error::Error>> {
+    if path.exists() {
+        Ok(Kanban::load_from_file(path)?)
+    } else {
+        Ok(Kanban::default())
+    }
 }
 
 pub fn run() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
 
-    dbg!(&cli);
+    let Some(command) = &cli.command else {
+        println!("Will launch future tui");
+        return Ok(());
+    };
+
+    let mut board = load_board(&cli.file)?;
+    board.apply_config(&Config::load_from_file(&cli.config)?);
 
-    match &cli.command {
-        Some(Command::List) => {
-            println!("List");
+    match command {
+        Command::List => {
+            println!("{}", board.render_table(terminal_width()));
+            return Ok(());
         }
-        Some(Command::Column(command)) => match command {
-            ColumnCommands::Add { title } => {
-                println!("Add column with title: {title}");
+        Command::Column(command) => match command {
+            ColumnCommands::Add { title, limit } => {
+                board.add_column_with_limit(title.clone(), *limit)?;
             }
             ColumnCommands::List { id } => {
-                println!("List column: {id}");
+                println!("{}", board.render_column_table(id, terminal_width())?);
+                return Ok(());
             }
             ColumnCommands::Remove { id } => {
-                println!("Remove column: {id}");
+                board.remove_column(id)?;
             }
             ColumnCommands::Rename { id, title } => {
-                println!("Rename column id: {id} to title: {title}");
+                board.rename_column(id, title)?;
             }
         },
-        Some(Command::Card(command)) => match command {
-            CardCommands::Add { column, title } => {
-                println!("Add card to column: {column} with title: {title}");
+        Command::Card(command) => match command {
+            CardCommands::Add { column, title, date } => {
+                let mut card = CardBuilder::new().column(column).title(title);
+                if let Some(date) = date {
+                    card = card.date(date);
+                }
+                board.add_card(&card.build()?)?;
             }
             CardCommands::Remove { id } => {
-                println!("Remove card: {id}");
+                board.remove_card(id)?;
             }
             CardCommands::Rename { id, title } => {
-                println!("Rename card id: {id} to title: {title}");
+                board.rename_card(id, title)?;
             }
             CardCommands::Move { id, column } => {
-                println!("Move card: {id} to column: {column}");
+                board.move_card(column, id)?;
+            }
+            CardCommands::Date { id, date } => {
+                board.set_card_date(id, date.as_deref())?;
             }
         },
-        None => {
-            println!("Will launch future tui")
-        }
     }
 
+    board.save_to_file(&cli.file)?;
+
     Ok(())
 }
@@ -0,0 +1,71 @@
+//! Board configuration loaded from a TOML file, e.g.:
+//!
+//! ```toml
+//! [columns."In Progress"]
+//! limit = 3
+//! ```
+
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// Per-column settings loaded from the config file.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ColumnConfig {
+    /// The column's WIP limit, if any.
+    pub limit: Option<usize>,
+}
+
+/// Top-level board configuration.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    pub columns: HashMap<String, ColumnConfig>,
+}
+
+impl Config {
+    /// Loads a config from `path`, or the default (no limits) config if the
+    /// file does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use kantui::Config;
+    /// let config = Config::load_from_file("kantui.toml").unwrap();
+    /// ```
+    pub fn load_from_file(path: impl AsRef<std::path::Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Config::default());
+        }
+        let raw = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        toml::from_str(&raw).map_err(|e| e.to_string())
+    }
+
+    /// The configured WIP limit for `column`, if any.
+    pub fn limit_for(&self, column: &str) -> Option<usize> {
+        self.columns.get(column).and_then(|c| c.limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_missing_file_is_default() {
+        let config = Config::load_from_file("/nonexistent/kantui.toml").unwrap();
+        assert!(config.columns.is_empty());
+    }
+
+    #[test]
+    fn test_load_from_file() {
+        let path = std::env::temp_dir().join("kantui_test_config.toml");
+        std::fs::write(&path, "[columns.\"In Progress\"]\nlimit = 3\n").unwrap();
+
+        let config = Config::load_from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(config.limit_for("In Progress"), Some(3));
+        assert_eq!(config.limit_for("Done"), None);
+    }
+}
@@ -1,5 +1,10 @@
 mod board;
+mod cache;
+mod config;
 
 pub use board::card::{Card, CardBuilder};
 pub use board::kanban::Kanban;
+pub use board::parse_error::{Diagnostic, ParseError};
 pub use board::status::Status;
+pub use cache::{BoardCache, Cached, CachedError};
+pub use config::Config;